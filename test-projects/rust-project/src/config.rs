@@ -0,0 +1,296 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Config {
+    pub name: String,
+    pub version: u32,
+}
+
+/// The file formats `Config` knows how to (de)serialize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Yaml,
+    Json,
+    Toml,
+}
+
+impl ConfigFormat {
+    /// Picks a format from a file extension, e.g. `yaml`, `yml`, `json`, `toml`.
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext.to_ascii_lowercase().as_str() {
+            "yaml" | "yml" => Some(ConfigFormat::Yaml),
+            "json" => Some(ConfigFormat::Json),
+            "toml" => Some(ConfigFormat::Toml),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The file extension didn't map to a known format.
+    UnknownFormat(String),
+    /// A `ConfigBuilder` was built without a base file set via `with_file`.
+    MissingBaseFile,
+    /// `Config::load_migrated` needed a migration from this version but none was registered.
+    MissingMigration(u32),
+    /// The octal quirk matched a `^0[0-7]+$` scalar that doesn't fit the target integer type.
+    InvalidOctalLiteral(String),
+    Io(std::io::Error),
+    Yaml(serde_yaml::Error),
+    Json(serde_json::Error),
+    Toml(toml::de::Error),
+    TomlSer(toml::ser::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::UnknownFormat(ext) => write!(f, "unknown config format: {ext}"),
+            ConfigError::MissingBaseFile => write!(f, "no base file set on ConfigBuilder"),
+            ConfigError::MissingMigration(version) => {
+                write!(f, "no migration registered from version {version}")
+            }
+            ConfigError::InvalidOctalLiteral(value) => {
+                write!(f, "octal quirk: `{value}` doesn't fit the target integer type")
+            }
+            ConfigError::Io(e) => write!(f, "io error: {e}"),
+            ConfigError::Yaml(e) => write!(f, "yaml error: {e}"),
+            ConfigError::Json(e) => write!(f, "json error: {e}"),
+            ConfigError::Toml(e) => write!(f, "toml error: {e}"),
+            ConfigError::TomlSer(e) => write!(f, "toml serialization error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(e: std::io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+impl From<serde_yaml::Error> for ConfigError {
+    fn from(e: serde_yaml::Error) -> Self {
+        ConfigError::Yaml(e)
+    }
+}
+
+impl From<serde_json::Error> for ConfigError {
+    fn from(e: serde_json::Error) -> Self {
+        ConfigError::Json(e)
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(e: toml::de::Error) -> Self {
+        ConfigError::Toml(e)
+    }
+}
+
+impl From<toml::ser::Error> for ConfigError {
+    fn from(e: toml::ser::Error) -> Self {
+        ConfigError::TomlSer(e)
+    }
+}
+
+impl Config {
+    /// Loads a `Config` from `path`, picking the deserializer from the file extension.
+    pub fn from_path(path: &Path) -> Result<Config, ConfigError> {
+        let format = format_for_path(path)?;
+        let contents = fs::read_to_string(path)?;
+        Config::from_str_with_format(&contents, format)
+    }
+
+    /// Deserializes a `Config` from a string using an explicit format.
+    pub fn from_str_with_format(contents: &str, format: ConfigFormat) -> Result<Config, ConfigError> {
+        let config = match format {
+            ConfigFormat::Yaml => serde_yaml::from_str(contents)?,
+            ConfigFormat::Json => serde_json::from_str(contents)?,
+            ConfigFormat::Toml => toml::from_str(contents)?,
+        };
+        Ok(config)
+    }
+
+    /// Loads a `Config` from `path` like [`Config::from_path`], applying `options`.
+    pub fn from_path_with_options(path: &Path, options: &ConfigOptions) -> Result<Config, ConfigError> {
+        let format = format_for_path(path)?;
+        let contents = fs::read_to_string(path)?;
+        Config::from_str_with_options(&contents, format, options)
+    }
+
+    /// Deserializes a `Config` from a string like [`Config::from_str_with_format`],
+    /// applying `options` (currently only [`ConfigOptions::octal_quirk`], which only
+    /// affects YAML input).
+    pub fn from_str_with_options(
+        contents: &str,
+        format: ConfigFormat,
+        options: &ConfigOptions,
+    ) -> Result<Config, ConfigError> {
+        if format == ConfigFormat::Yaml && options.octal_quirk {
+            let rewritten = apply_octal_quirk(contents)?;
+            Config::from_str_with_format(&rewritten, format)
+        } else {
+            Config::from_str_with_format(contents, format)
+        }
+    }
+
+    /// Serializes this `Config` and writes it to `path`, picking the format from its extension.
+    pub fn to_path(&self, path: &Path) -> Result<(), ConfigError> {
+        let format = format_for_path(path)?;
+        let contents = self.to_string_with_format(format)?;
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Serializes this `Config` to a string in the given format.
+    pub fn to_string_with_format(&self, format: ConfigFormat) -> Result<String, ConfigError> {
+        let contents = match format {
+            ConfigFormat::Yaml => serde_yaml::to_string(self)?,
+            ConfigFormat::Json => serde_json::to_string_pretty(self)?,
+            ConfigFormat::Toml => toml::to_string_pretty(self)?,
+        };
+        Ok(contents)
+    }
+}
+
+pub(crate) fn format_for_path(path: &Path) -> Result<ConfigFormat, ConfigError> {
+    let ext = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default();
+    ConfigFormat::from_extension(ext).ok_or_else(|| ConfigError::UnknownFormat(ext.to_string()))
+}
+
+/// Options tweaking how the config loader parses its input.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConfigOptions {
+    /// When set, bare YAML integer scalars with a leading zero (e.g. `0755`)
+    /// in `Config`'s known integer fields (currently just `version`, see
+    /// [`INTEGER_FIELDS`]) are read as base-8, matching the quirk some
+    /// Go-based YAML consumers have. Off by default, so strict YAML 1.2
+    /// behavior applies unless a caller opts in: such a scalar is then a
+    /// string, which an integer field rejects.
+    pub octal_quirk: bool,
+}
+
+/// Fields of `Config` that are integers, and therefore candidates for the
+/// octal quirk. A scalar matching `^0[0-7]+$` in any other field (e.g. a
+/// `name` that happens to look like an octal literal) is left alone.
+///
+/// This is a fixed allowlist, not a generic scan of the parsed YAML tree: it
+/// only ever looks at `key: value` lines whose key is listed here, matched by
+/// text rather than by walking the document structure. Adding a new integer
+/// field to `Config` that should support the quirk means adding its name here
+/// too — it won't be picked up automatically.
+const INTEGER_FIELDS: &[&str] = &["version"];
+
+/// Rewrites bare `^0[0-7]+$` scalars in [`INTEGER_FIELDS`] to their decimal
+/// value so serde reads them as the octal number instead of as the string
+/// YAML itself makes of a leading-zero literal.
+fn apply_octal_quirk(contents: &str) -> Result<String, ConfigError> {
+    let lines = contents
+        .lines()
+        .map(rewrite_octal_line)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(lines.join("\n"))
+}
+
+fn rewrite_octal_line(line: &str) -> Result<String, ConfigError> {
+    let Some(colon) = line.find(':') else {
+        return Ok(line.to_string());
+    };
+    let (key, rest) = line.split_at(colon);
+    if !INTEGER_FIELDS.contains(&key.trim()) {
+        return Ok(line.to_string());
+    }
+    let value = rest[1..].trim();
+    if is_octal_literal(value) {
+        let decimal = u32::from_str_radix(&value[1..], 8)
+            .map_err(|_| ConfigError::InvalidOctalLiteral(value.to_string()))?;
+        Ok(format!("{key}: {decimal}"))
+    } else {
+        Ok(line.to_string())
+    }
+}
+
+fn is_octal_literal(value: &str) -> bool {
+    value.len() > 1 && value.starts_with('0') && value[1..].bytes().all(|b| (b'0'..=b'7').contains(&b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_yaml() {
+        let config = Config {
+            name: "svc".to_string(),
+            version: 3,
+        };
+        let yaml = config.to_string_with_format(ConfigFormat::Yaml).unwrap();
+        let parsed = Config::from_str_with_format(&yaml, ConfigFormat::Yaml).unwrap();
+        assert_eq!(parsed.name, "svc");
+        assert_eq!(parsed.version, 3);
+    }
+
+    #[test]
+    fn rejects_unknown_extension() {
+        let err = format_for_path(Path::new("config.ini")).unwrap_err();
+        assert!(matches!(err, ConfigError::UnknownFormat(ext) if ext == "ini"));
+    }
+
+    #[test]
+    fn from_str_with_format_reports_yaml_errors() {
+        let err = Config::from_str_with_format("name: [", ConfigFormat::Yaml).unwrap_err();
+        assert!(matches!(err, ConfigError::Yaml(_)));
+    }
+
+    #[test]
+    fn octal_quirk_reinterprets_leading_zero_version() {
+        let options = ConfigOptions { octal_quirk: true };
+        let config =
+            Config::from_str_with_options("name: test\nversion: 0755\n", ConfigFormat::Yaml, &options)
+                .unwrap();
+        assert_eq!(config.version, 0o755);
+    }
+
+    #[test]
+    fn octal_quirk_reports_overflowing_literals_instead_of_defaulting_to_zero() {
+        let options = ConfigOptions { octal_quirk: true };
+        let err = Config::from_str_with_options(
+            "name: test\nversion: 07777777777777777777777\n",
+            ConfigFormat::Yaml,
+            &options,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ConfigError::InvalidOctalLiteral(_)));
+    }
+
+    #[test]
+    fn octal_quirk_does_not_touch_non_integer_fields() {
+        let options = ConfigOptions { octal_quirk: true };
+        let config =
+            Config::from_str_with_options("name: 0755\nversion: 1\n", ConfigFormat::Yaml, &options)
+                .unwrap();
+        assert_eq!(config.name, "0755");
+        assert_eq!(config.version, 1);
+    }
+
+    #[test]
+    fn octal_quirk_is_off_by_default() {
+        let options = ConfigOptions::default();
+        // Without the quirk, YAML treats a leading-zero scalar as ambiguous and
+        // refuses to read it as a `u32` at all, rather than silently picking
+        // decimal or octal — this is exactly the failure the quirk exists to fix.
+        let err =
+            Config::from_str_with_options("name: test\nversion: 0755\n", ConfigFormat::Yaml, &options)
+                .unwrap_err();
+        assert!(matches!(err, ConfigError::Yaml(_)));
+    }
+}
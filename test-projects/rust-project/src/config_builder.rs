@@ -0,0 +1,164 @@
+use std::path::{Path, PathBuf};
+
+use crate::config::{Config, ConfigError, ConfigFormat};
+
+/// Assembles a `Config` from a base file, optional overlay files, and
+/// environment-variable overrides.
+///
+/// Overlays are deep-merged on top of the base file in the order they were
+/// added, so a partial overlay only replaces the fields it specifies and
+/// leaves the rest of the base document intact (handy for keeping a secret or
+/// token in its own file, separate from the main config). Environment
+/// variables following the `<PREFIX>_<FIELD>` convention are applied last and
+/// win over both the base file and every overlay.
+#[derive(Debug, Default)]
+pub struct ConfigBuilder {
+    base: Option<PathBuf>,
+    overlays: Vec<PathBuf>,
+    env_prefix: Option<String>,
+}
+
+impl ConfigBuilder {
+    pub fn new() -> Self {
+        ConfigBuilder::default()
+    }
+
+    /// Sets the base config file. Required before calling [`ConfigBuilder::build`].
+    pub fn with_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.base = Some(path.into());
+        self
+    }
+
+    /// Adds an overlay file to deep-merge on top of the base, in call order.
+    pub fn with_overlay(mut self, path: impl Into<PathBuf>) -> Self {
+        self.overlays.push(path.into());
+        self
+    }
+
+    /// Applies environment-variable overrides named `<PREFIX>_<FIELD>`
+    /// (e.g. prefix `APP` overrides `name` via `APP_NAME`).
+    pub fn with_env_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.env_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Loads the base file, merges the overlays on top, applies environment
+    /// overrides, and deserializes the result into a `Config`.
+    pub fn build(self) -> Result<Config, ConfigError> {
+        let base_path = self.base.ok_or(ConfigError::MissingBaseFile)?;
+        let mut value = value_from_path(&base_path)?;
+
+        for overlay in &self.overlays {
+            let overlay_value = value_from_path(overlay)?;
+            merge(&mut value, overlay_value);
+        }
+
+        if let Some(prefix) = &self.env_prefix {
+            apply_env_overrides(&mut value, prefix);
+        }
+
+        let config = serde_json::from_value(value)?;
+        Ok(config)
+    }
+}
+
+fn value_from_path(path: &Path) -> Result<serde_json::Value, ConfigError> {
+    let format = crate::config::format_for_path(path)?;
+    let contents = std::fs::read_to_string(path)?;
+    let value = match format {
+        ConfigFormat::Yaml => serde_yaml::from_str(&contents)?,
+        ConfigFormat::Json => serde_json::from_str(&contents)?,
+        ConfigFormat::Toml => toml::from_str(&contents)?,
+    };
+    Ok(value)
+}
+
+/// Deep-merges `overlay` into `base`, with `overlay`'s values winning per field.
+fn merge(base: &mut serde_json::Value, overlay: serde_json::Value) {
+    match (base, overlay) {
+        (serde_json::Value::Object(base_map), serde_json::Value::Object(overlay_map)) => {
+            for (key, value) in overlay_map {
+                merge(base_map.entry(key).or_insert(serde_json::Value::Null), value);
+            }
+        }
+        (base_slot, overlay_value) => *base_slot = overlay_value,
+    }
+}
+
+/// Overwrites top-level fields of `value` with environment variables named
+/// `<PREFIX>_<FIELD>` (field name upper-cased), coercing the variable's text
+/// to match the field's existing JSON type.
+fn apply_env_overrides(value: &mut serde_json::Value, prefix: &str) {
+    let serde_json::Value::Object(map) = value else {
+        return;
+    };
+    for (key, slot) in map.iter_mut() {
+        let env_key = format!("{prefix}_{}", key.to_uppercase());
+        if let Ok(raw) = std::env::var(env_key) {
+            *slot = coerce_env_value(slot, &raw);
+        }
+    }
+}
+
+fn coerce_env_value(existing: &serde_json::Value, raw: &str) -> serde_json::Value {
+    match existing {
+        serde_json::Value::Number(_) => raw
+            .parse::<i64>()
+            .map(serde_json::Value::from)
+            .unwrap_or_else(|_| serde_json::Value::String(raw.to_string())),
+        serde_json::Value::Bool(_) => raw
+            .parse::<bool>()
+            .map(serde_json::Value::Bool)
+            .unwrap_or_else(|_| serde_json::Value::String(raw.to_string())),
+        _ => serde_json::Value::String(raw.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_without_a_base_file_fails() {
+        let err = ConfigBuilder::new().build().unwrap_err();
+        assert!(matches!(err, ConfigError::MissingBaseFile));
+    }
+
+    #[test]
+    fn overlay_only_replaces_the_fields_it_specifies() {
+        let base = std::env::temp_dir().join("yeah-action-builder-base-test.yaml");
+        let overlay = std::env::temp_dir().join("yeah-action-builder-overlay-test.yaml");
+        std::fs::write(&base, "name: base\nversion: 1\n").unwrap();
+        std::fs::write(&overlay, "version: 2\n").unwrap();
+
+        let config = ConfigBuilder::new()
+            .with_file(&base)
+            .with_overlay(&overlay)
+            .build()
+            .unwrap();
+
+        assert_eq!(config.name, "base");
+        assert_eq!(config.version, 2);
+    }
+
+    #[test]
+    fn env_override_wins_over_file_and_overlay() {
+        let base = std::env::temp_dir().join("yeah-action-builder-env-test.yaml");
+        let overlay = std::env::temp_dir().join("yeah-action-builder-env-overlay-test.yaml");
+        std::fs::write(&base, "name: base\nversion: 1\n").unwrap();
+        std::fs::write(&overlay, "version: 2\n").unwrap();
+
+        let env_key = "YEAHACTIONTEST_VERSION";
+        std::env::set_var(env_key, "9");
+
+        let config = ConfigBuilder::new()
+            .with_file(&base)
+            .with_overlay(&overlay)
+            .with_env_prefix("YEAHACTIONTEST")
+            .build()
+            .unwrap();
+
+        std::env::remove_var(env_key);
+        assert_eq!(config.version, 9);
+    }
+}
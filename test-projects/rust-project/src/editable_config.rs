@@ -0,0 +1,178 @@
+use std::fs;
+use std::path::Path;
+
+use crate::config::{Config, ConfigError, ConfigFormat};
+
+/// One line of the original document, in source order.
+#[derive(Debug, Clone)]
+enum Line {
+    /// A comment line, reproduced verbatim.
+    Comment(String),
+    /// A `key: value` (or `key = value`) line for a field `EditableConfig`
+    /// knows how to write; the value is re-derived from `Config` on
+    /// serialization so edits to the struct show up here.
+    Key(String),
+    /// Any other line — blank, or a field `Config` doesn't model — reproduced
+    /// verbatim so loading a file with extra fields doesn't fail or lose data.
+    Raw(String),
+}
+
+/// A `Config` loaded alongside the comments and key order of its source file.
+///
+/// Plain `Config::from_path`/`to_path` round-trip through serde, which forgets
+/// comments and re-orders fields. `EditableConfig` keeps a sidecar of the
+/// original lines — comments and keys interleaved in source order — so that
+/// mutating a single field (e.g. bumping `version`) and writing the file back
+/// out produces a minimal diff instead of rewriting the whole document, and a
+/// comment line stays attached to the field it was written next to. Only
+/// whole-line comments are kept: a trailing comment on a `name` or `version`
+/// line is dropped when that line is rewritten.
+#[derive(Debug)]
+pub struct EditableConfig {
+    pub config: Config,
+    format: ConfigFormat,
+    lines: Vec<Line>,
+}
+
+impl EditableConfig {
+    /// Loads an `EditableConfig` from `path`, picking the format from its extension.
+    pub fn from_path(path: &Path) -> Result<EditableConfig, ConfigError> {
+        let format = crate::config::format_for_path(path)?;
+        let contents = fs::read_to_string(path)?;
+        EditableConfig::from_str_with_format(&contents, format)
+    }
+
+    /// Parses `contents` in the given format into a `Config` plus its sidecar metadata.
+    pub fn from_str_with_format(
+        contents: &str,
+        format: ConfigFormat,
+    ) -> Result<EditableConfig, ConfigError> {
+        let config = Config::from_str_with_format(contents, format)?;
+
+        let mut lines = Vec::new();
+        let mut in_toml_table = false;
+        for line in contents.lines() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with('#') {
+                lines.push(Line::Comment(line.to_string()));
+                continue;
+            }
+            if format == ConfigFormat::Toml && trimmed.starts_with('[') {
+                in_toml_table = true;
+            }
+            // Only top-level keys belong to `Config`: unindented YAML lines,
+            // and TOML lines before the first table header.
+            let top_level = match format {
+                ConfigFormat::Toml => !in_toml_table,
+                _ => line.len() == trimmed.len(),
+            };
+            let key = trimmed
+                .split_once([':', '='])
+                .filter(|_| top_level)
+                .map(|(key, _)| key.trim().trim_matches('"'));
+            match key {
+                Some("name") => lines.push(Line::Key("name".to_string())),
+                Some("version") => lines.push(Line::Key("version".to_string())),
+                _ => lines.push(Line::Raw(line.to_string())),
+            }
+        }
+
+        Ok(EditableConfig {
+            config,
+            format,
+            lines,
+        })
+    }
+
+    /// Sets the `version` field, to be re-serialized while preserving comments and key order.
+    pub fn set_version(&mut self, version: u32) {
+        self.config.version = version;
+    }
+
+    /// Sets the `name` field, to be re-serialized while preserving comments and key order.
+    pub fn set_name(&mut self, name: String) {
+        self.config.name = name;
+    }
+
+    /// Re-serializes the config, re-emitting comments in their original
+    /// position relative to the keys so unrelated lines don't move in the diff.
+    ///
+    /// JSON input is not kept line by line: it is re-serialized from `Config`
+    /// alone, so its key order, formatting and any fields `Config` doesn't
+    /// model are not preserved.
+    pub fn to_string(&self) -> Result<String, ConfigError> {
+        if self.format == ConfigFormat::Json {
+            return self.config.to_string_with_format(self.format);
+        }
+
+        let separator = match self.format {
+            ConfigFormat::Yaml => ": ",
+            ConfigFormat::Toml => " = ",
+            ConfigFormat::Json => unreachable!("handled above"),
+        };
+
+        let mut out = String::new();
+        for line in &self.lines {
+            match line {
+                Line::Comment(comment) | Line::Raw(comment) => out.push_str(comment),
+                Line::Key(key) => {
+                    out.push_str(key);
+                    out.push_str(separator);
+                    out.push_str(&self.field_value(key));
+                }
+            }
+            out.push('\n');
+        }
+        Ok(out)
+    }
+
+    /// Writes the re-serialized document back to `path`.
+    pub fn to_path(&self, path: &Path) -> Result<(), ConfigError> {
+        let contents = self.to_string()?;
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Returns the current value for a `Line::Key`, which is only ever
+    /// constructed for `"name"` or `"version"` (see `from_str_with_format`).
+    fn field_value(&self, key: &str) -> String {
+        match key {
+            "name" => match self.format {
+                ConfigFormat::Toml => format!("\"{}\"", self.config.name),
+                _ => self.config.name.clone(),
+            },
+            "version" => self.config.version.to_string(),
+            other => unreachable!("Line::Key is only constructed for known fields, got {other}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_comments_attached_to_their_field_on_edit() {
+        let yaml = "# header\nname: test\n# bump this when schema changes\nversion: 1\n";
+        let mut config = EditableConfig::from_str_with_format(yaml, ConfigFormat::Yaml).unwrap();
+
+        config.set_version(2);
+        let rewritten = config.to_string().unwrap();
+
+        assert_eq!(
+            rewritten,
+            "# header\nname: test\n# bump this when schema changes\nversion: 2\n"
+        );
+    }
+
+    #[test]
+    fn passes_through_unrecognized_fields_verbatim() {
+        let yaml = "name: svc\nversion: 1\nextra: 5\n";
+        let mut config = EditableConfig::from_str_with_format(yaml, ConfigFormat::Yaml).unwrap();
+
+        config.set_version(2);
+        let rewritten = config.to_string().unwrap();
+
+        assert_eq!(rewritten, "name: svc\nversion: 2\nextra: 5\n");
+    }
+}
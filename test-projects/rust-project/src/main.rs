@@ -1,15 +1,71 @@
-use serde::{Deserialize, Serialize};
+mod config;
+mod config_builder;
+mod editable_config;
+mod migration;
 
-#[derive(Serialize, Deserialize, Debug)]
-struct Config {
-    name: String,
-    version: u32,
+use std::env;
+
+use config::{Config, ConfigFormat, ConfigOptions};
+use config_builder::ConfigBuilder;
+use editable_config::EditableConfig;
+use migration::CURRENT_SCHEMA_VERSION;
+
+/// Upgrades a legacy (unversioned) config to the current schema by filling
+/// in the fields it's missing.
+fn migrate_legacy_to_v1(mut value: serde_json::Value) -> serde_json::Value {
+    if let serde_json::Value::Object(map) = &mut value {
+        map.entry("name".to_string())
+            .or_insert_with(|| serde_json::Value::String("legacy".to_string()));
+        map.insert("version".to_string(), serde_json::Value::from(CURRENT_SCHEMA_VERSION));
+    }
+    value
 }
 
-fn main() {
+fn main() -> Result<(), Box<dyn std::error::Error>> {
     let config = Config {
         name: String::from("test"),
         version: 1,
     };
     println!("test rust project: {:?}", config);
+
+    let path = env::temp_dir().join("yeah-action-demo.yaml");
+    config.to_path(&path)?;
+    let reloaded = Config::from_path(&path)?;
+    println!("reloaded from {}: {:?}", path.display(), reloaded);
+
+    let mut editable = EditableConfig::from_path(&path)?;
+    editable.set_version(editable.config.version + 1);
+    editable.set_name(String::from("renamed"));
+    editable.to_path(&path)?;
+    println!("bumped version in place at {}", path.display());
+
+    migration::register_migration(0, migrate_legacy_to_v1);
+    let legacy_path = env::temp_dir().join("yeah-action-demo-legacy.yaml");
+    std::fs::write(&legacy_path, "version: 0\n")?;
+    let migrated = Config::load_migrated(&legacy_path)?;
+    println!("migrated legacy config: {:?}", migrated);
+
+    let octal_options = ConfigOptions { octal_quirk: true };
+    let go_style = Config::from_str_with_options(
+        "name: test\nversion: 0755\n",
+        ConfigFormat::Yaml,
+        &octal_options,
+    )?;
+    println!("parsed with octal quirk: {:?}", go_style);
+
+    std::fs::write(&path, "name: test\nversion: 0755\n")?;
+    let go_style_from_path = Config::from_path_with_options(&path, &octal_options)?;
+    println!("parsed from path with octal quirk: {:?}", go_style_from_path);
+
+    let overlay_path = env::temp_dir().join("yeah-action-demo-overlay.yaml");
+    std::fs::write(&overlay_path, "version: 42\n")?;
+    env::set_var("APP_NAME", "from-env");
+    let layered = ConfigBuilder::new()
+        .with_file(&path)
+        .with_overlay(&overlay_path)
+        .with_env_prefix("APP")
+        .build()?;
+    println!("layered config: {:?}", layered);
+
+    Ok(())
 }
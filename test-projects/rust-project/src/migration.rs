@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+use crate::config::{Config, ConfigError, ConfigFormat};
+
+/// The schema version `Config` documents are migrated up to.
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// Upgrades a document from schema version `N` to `N + 1`.
+///
+/// Implementations are expected to set `"version"` to `N + 1` on the value
+/// they return, since that's what drives the next iteration of the chain.
+pub type MigrationFn = fn(serde_json::Value) -> serde_json::Value;
+
+fn registry() -> &'static Mutex<BTreeMap<u32, MigrationFn>> {
+    static REGISTRY: OnceLock<Mutex<BTreeMap<u32, MigrationFn>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(BTreeMap::new()))
+}
+
+/// Registers a migration that upgrades a document from schema version `from` to `from + 1`.
+pub fn register_migration(from: u32, f: MigrationFn) {
+    registry().lock().unwrap().insert(from, f);
+}
+
+impl Config {
+    /// Loads `path`, applying any registered migrations needed to bring it up
+    /// to [`CURRENT_SCHEMA_VERSION`]. If a migration actually ran, the upgraded
+    /// document is written back to disk so it only runs once; a file that was
+    /// already current is left untouched.
+    pub fn load_migrated(path: &Path) -> Result<Config, ConfigError> {
+        let format = crate::config::format_for_path(path)?;
+        let contents = std::fs::read_to_string(path)?;
+        let mut value = value_from_str(&contents, format)?;
+
+        let original_version = version_of(&value);
+        let migrations = registry().lock().unwrap();
+        let mut version = original_version;
+        while version < CURRENT_SCHEMA_VERSION {
+            let migrate = migrations
+                .get(&version)
+                .ok_or(ConfigError::MissingMigration(version))?;
+            value = migrate(value);
+            version = version_of(&value);
+        }
+        drop(migrations);
+
+        let config: Config = serde_json::from_value(value)?;
+        if version != original_version {
+            config.to_path(path)?;
+        }
+        Ok(config)
+    }
+}
+
+fn version_of(value: &serde_json::Value) -> u32 {
+    value
+        .get("version")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u32
+}
+
+fn value_from_str(contents: &str, format: ConfigFormat) -> Result<serde_json::Value, ConfigError> {
+    let value = match format {
+        ConfigFormat::Yaml => serde_yaml::from_str(contents)?,
+        ConfigFormat::Json => serde_json::from_str(contents)?,
+        ConfigFormat::Toml => toml::from_str(contents)?,
+    };
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migrate_v0_to_v1(mut value: serde_json::Value) -> serde_json::Value {
+        if let serde_json::Value::Object(map) = &mut value {
+            map.entry("name".to_string())
+                .or_insert_with(|| serde_json::Value::String("unnamed".to_string()));
+            map.insert("version".to_string(), serde_json::Value::from(1));
+        }
+        value
+    }
+
+    #[test]
+    fn applies_registered_migration_and_writes_back() {
+        register_migration(0, migrate_v0_to_v1);
+        let path = std::env::temp_dir().join("yeah-action-migration-test.yaml");
+        std::fs::write(&path, "version: 0\n").unwrap();
+
+        let config = Config::load_migrated(&path).unwrap();
+        assert_eq!(config.version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(config.name, "unnamed");
+
+        let persisted = std::fs::read_to_string(&path).unwrap();
+        assert!(persisted.contains("version: 1"));
+    }
+
+    #[test]
+    fn leaves_an_already_current_file_untouched() {
+        let path = std::env::temp_dir().join("yeah-action-migration-noop-test.yaml");
+        let original = "# a cherished comment\nname: svc\nversion: 1\n";
+        std::fs::write(&path, original).unwrap();
+
+        let config = Config::load_migrated(&path).unwrap();
+        assert_eq!(config.name, "svc");
+
+        let persisted = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(persisted, original);
+    }
+
+    #[test]
+    fn surfaces_parse_errors_before_attempting_migration() {
+        let path = std::env::temp_dir().join("yeah-action-migration-invalid-test.yaml");
+        std::fs::write(&path, "version: [unterminated\n").unwrap();
+
+        let err = Config::load_migrated(&path).unwrap_err();
+        assert!(matches!(err, ConfigError::Yaml(_)));
+    }
+
+    #[test]
+    fn version_of_defaults_to_zero_when_missing() {
+        let value = serde_json::json!({ "name": "svc" });
+        assert_eq!(version_of(&value), 0);
+    }
+}